@@ -20,9 +20,17 @@
 //! ## Asynchronous Runtime
 //!
 //! * `async-runtime-tokio`: Enables async interface for Tokio runtime.
+//! * `async-runtime-async-std`: Enables async interface for the `futures`/async-std runtime.
 //!
 //! By default, neither of these features is enabled.
 //!
+//! ## Hash Backends
+//!
+//! * `digest`: Bridges the [RustCrypto `digest::Digest`](digest::Digest) trait to [`Hash`] via
+//!   [`DigestHash`], so hashers such as `sha2` or `blake3` work with [`Reader`] out of the box.
+//!
+//! By default, this feature is not enabled.
+//!
 //! # Usage
 //!
 //! ```rust,ignore
@@ -62,15 +70,27 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![forbid(unsafe_code)]
 
+#[cfg(all(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+compile_error!("features `async-runtime-tokio` and `async-runtime-async-std` are mutually exclusive");
+
+use std::fmt;
 use std::io::{self, BufRead, Read};
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+use std::pin::Pin;
 #[cfg(feature = "async-runtime-tokio")]
-use std::pin::{pin, Pin};
-#[cfg(feature = "async-runtime-tokio")]
+use std::pin::pin;
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
 use std::task::{Context, Poll};
 
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+use bytes::Bytes;
 use chksum_core::Hash;
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+use futures_core::Stream;
+#[cfg(feature = "async-runtime-async-std")]
+use futures_io::AsyncRead as FuturesAsyncRead;
 #[cfg(feature = "async-runtime-tokio")]
-use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
+use tokio::io::{AsyncRead, ReadBuf};
 
 /// Creates new [`Reader`].
 pub fn new<R, H>(inner: R) -> Reader<R, H>
@@ -90,21 +110,19 @@ where
     Reader::with_hash(inner, hash)
 }
 
-#[cfg(feature = "async-runtime-tokio")]
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
 /// Creates new [`AsyncReader`].
 pub fn async_new<R, H>(inner: R) -> AsyncReader<R, H>
 where
-    R: AsyncReadExt,
     H: Hash,
 {
     AsyncReader::new(inner)
 }
 
-#[cfg(feature = "async-runtime-tokio")]
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
 /// Creates new [`AsyncReader`] with provided hash.
 pub fn async_with_hash<R, H>(inner: R, hash: H) -> AsyncReader<R, H>
 where
-    R: AsyncReadExt,
     H: Hash,
 {
     AsyncReader::with_hash(inner, hash)
@@ -119,6 +137,8 @@ where
 {
     inner: R,
     hash: H,
+    expected: Option<String>,
+    checked: bool,
 }
 
 impl<R, H> Reader<R, H>
@@ -135,7 +155,20 @@ where
     /// Creates new [`Reader`] with provided hash.
     #[must_use]
     pub const fn with_hash(inner: R, hash: H) -> Self {
-        Self { inner, hash }
+        Self {
+            inner,
+            hash,
+            expected: None,
+            checked: false,
+        }
+    }
+
+    /// Creates new [`Reader`] that verifies the computed digest against `expected` at EOF.
+    #[must_use]
+    pub fn with_expected(inner: R, expected: H::Digest) -> Self {
+        let mut reader = Self::new(inner);
+        reader.expected = Some(expected.to_string());
+        reader
     }
 
     /// Unwraps this [`Reader`], returning the underlying reader.
@@ -150,6 +183,20 @@ where
     pub fn digest(&self) -> H::Digest {
         self.hash.digest()
     }
+
+    /// Verifies the computed digest against the expected one.
+    ///
+    /// Returns an [`io::Error`] of kind [`InvalidData`](io::ErrorKind::InvalidData) when a digest was
+    /// supplied via [`with_expected`](Self::with_expected) and it does not match the computed one. When no
+    /// expected digest was supplied this is a no-op.
+    pub fn verify(&self) -> io::Result<()> {
+        match &self.expected {
+            Some(expected) if self.digest().to_string() != *expected => {
+                Err(io::Error::new(io::ErrorKind::InvalidData, "digest mismatch"))
+            },
+            _ => Ok(()),
+        }
+    }
 }
 
 impl<R, H> Read for Reader<R, H>
@@ -158,8 +205,15 @@ where
     H: Hash,
 {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        let n = self.inner.read(buf)?;
-        self.hash.update(&buf[..n]);
+        // A zero-length read only signals EOF when the caller actually offered capacity; a
+        // zero-capacity buffer also yields `Ok(0)` without the stream having ended.
+        let had_capacity = !buf.is_empty();
+        let hash = &mut self.hash;
+        let n = InspectReader::new(&mut self.inner, |bytes: &[u8]| hash.update(bytes)).read(buf)?;
+        if n == 0 && had_capacity && !self.checked {
+            self.checked = true;
+            self.verify()?;
+        }
         Ok(n)
     }
 }
@@ -179,21 +233,21 @@ where
 }
 
 /// Wraps a reader and calculates the hash digest on the fly.
-#[cfg(feature = "async-runtime-tokio")]
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AsyncReader<R, H>
 where
-    R: AsyncReadExt,
     H: Hash,
 {
     inner: R,
     hash: H,
+    expected: Option<String>,
+    checked: bool,
 }
 
-#[cfg(feature = "async-runtime-tokio")]
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
 impl<R, H> AsyncReader<R, H>
 where
-    R: AsyncReadExt,
     H: Hash,
 {
     /// Creates new [`AsyncReader`].
@@ -205,7 +259,20 @@ where
     /// Creates new [`AsyncReader`] with provided hash.
     #[must_use]
     pub const fn with_hash(inner: R, hash: H) -> Self {
-        Self { inner, hash }
+        Self {
+            inner,
+            hash,
+            expected: None,
+            checked: false,
+        }
+    }
+
+    /// Creates new [`AsyncReader`] that verifies the computed digest against `expected` at EOF.
+    #[must_use]
+    pub fn with_expected(inner: R, expected: H::Digest) -> Self {
+        let mut reader = Self::new(inner);
+        reader.expected = Some(expected.to_string());
+        reader
     }
 
     /// Unwraps this [`AsyncReader`], returning the underlying reader.
@@ -220,6 +287,133 @@ where
     pub fn digest(&self) -> H::Digest {
         self.hash.digest()
     }
+
+    /// Verifies the computed digest against the expected one.
+    ///
+    /// Returns an [`io::Error`] of kind [`InvalidData`](io::ErrorKind::InvalidData) when a digest was
+    /// supplied via [`with_expected`](Self::with_expected) and it does not match the computed one. When no
+    /// expected digest was supplied this is a no-op.
+    pub fn verify(&self) -> io::Result<()> {
+        match &self.expected {
+            Some(expected) if self.digest().to_string() != *expected => {
+                Err(io::Error::new(io::ErrorKind::InvalidData, "digest mismatch"))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Converts this [`AsyncReader`] into a [`Stream`] of byte chunks that hashes as it yields.
+    #[must_use]
+    pub fn into_stream(self) -> ReaderStream<R, H> {
+        ReaderStream::new(self)
+    }
+
+    /// Converts this [`AsyncReader`] into a [`Stream`] with the provided chunk capacity.
+    #[must_use]
+    pub fn into_stream_with_capacity(self, capacity: usize) -> ReaderStream<R, H> {
+        ReaderStream::with_capacity(self, capacity)
+    }
+}
+
+/// Default chunk capacity used by [`ReaderStream`].
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+const DEFAULT_STREAM_CAPACITY: usize = 8 * 1024;
+
+/// Converts an [`AsyncReader`] into a [`Stream`] of byte chunks, hashing each chunk as it is yielded.
+///
+/// The stream yields [`Bytes`] chunks of the freshly read region and completes once the underlying
+/// reader reaches EOF. The final digest stays retrievable via [`digest`](Self::digest) after the
+/// stream is exhausted.
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+#[derive(Clone, Debug)]
+pub struct ReaderStream<R, H>
+where
+    H: Hash,
+{
+    reader: AsyncReader<R, H>,
+    buffer: Vec<u8>,
+}
+
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+impl<R, H> ReaderStream<R, H>
+where
+    H: Hash,
+{
+    /// Creates new [`ReaderStream`] with the default chunk capacity.
+    #[must_use]
+    pub fn new(reader: AsyncReader<R, H>) -> Self {
+        Self::with_capacity(reader, DEFAULT_STREAM_CAPACITY)
+    }
+
+    /// Creates new [`ReaderStream`] with the provided chunk capacity.
+    ///
+    /// A requested capacity of `0` is bumped to `1`: an empty chunk buffer can never make
+    /// progress, and would make every poll look like an immediate, spurious EOF.
+    #[must_use]
+    pub fn with_capacity(reader: AsyncReader<R, H>, capacity: usize) -> Self {
+        Self {
+            reader,
+            buffer: vec![0u8; capacity.max(1)],
+        }
+    }
+
+    /// Returns calculated hash digest.
+    #[must_use]
+    pub fn digest(&self) -> H::Digest {
+        self.reader.digest()
+    }
+
+    /// Unwraps this [`ReaderStream`], returning the underlying [`AsyncReader`].
+    #[must_use]
+    pub fn into_inner(self) -> AsyncReader<R, H> {
+        let Self { reader, .. } = self;
+        reader
+    }
+}
+
+#[cfg(feature = "async-runtime-tokio")]
+impl<R, H> Stream for ReaderStream<R, H>
+where
+    R: AsyncRead + Unpin,
+    H: Hash + Unpin,
+{
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let mut buf = ReadBuf::new(&mut this.buffer);
+        match Pin::new(&mut this.reader).poll_read(cx, &mut buf) {
+            Poll::Ready(Ok(())) => {
+                let filled = buf.filled();
+                if filled.is_empty() {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Ready(Some(Ok(Bytes::copy_from_slice(filled))))
+                }
+            },
+            Poll::Ready(Err(err)) => Poll::Ready(Some(Err(err))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "async-runtime-async-std")]
+impl<R, H> Stream for ReaderStream<R, H>
+where
+    R: FuturesAsyncRead + Unpin,
+    H: Hash + Unpin,
+{
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        match Pin::new(&mut this.reader).poll_read(cx, &mut this.buffer) {
+            Poll::Ready(Ok(0)) => Poll::Ready(None),
+            Poll::Ready(Ok(n)) => Poll::Ready(Some(Ok(Bytes::copy_from_slice(&this.buffer[..n])))),
+            Poll::Ready(Err(err)) => Poll::Ready(Some(Err(err))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
 }
 
 #[cfg(feature = "async-runtime-tokio")]
@@ -229,13 +423,785 @@ where
     H: Hash + Unpin,
 {
     fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
-        let Self { inner, hash } = self.get_mut();
+        let this = self.get_mut();
+        let before = buf.filled().len();
+        // EOF is only genuine when the buffer had spare capacity; a full/zero-capacity `ReadBuf`
+        // also yields `Ok(())` with no new bytes without the stream having ended.
+        let had_capacity = buf.remaining() > 0;
+        let hash = &mut this.hash;
+        let mut inspect = AsyncInspectReader::new(&mut this.inner, |bytes: &[u8]| hash.update(bytes));
+        match Pin::new(&mut inspect).poll_read(cx, buf) {
+            Poll::Ready(Ok(())) => {
+                if buf.filled().len() == before && had_capacity && !this.checked {
+                    this.checked = true;
+                    if let Some(expected) = this.expected.as_ref() {
+                        if this.hash.digest().to_string() != *expected {
+                            return Poll::Ready(Err(io::Error::new(io::ErrorKind::InvalidData, "digest mismatch")));
+                        }
+                    }
+                }
+                Poll::Ready(Ok(()))
+            },
+            poll => poll,
+        }
+    }
+}
+
+#[cfg(feature = "async-runtime-async-std")]
+impl<R, H> FuturesAsyncRead for AsyncReader<R, H>
+where
+    R: FuturesAsyncRead + Unpin,
+    H: Hash + Unpin,
+{
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        // A zero-length read only signals EOF when the caller offered capacity; an empty
+        // destination buffer also yields `Ok(0)` without the stream having ended.
+        let had_capacity = !buf.is_empty();
+        let hash = &mut this.hash;
+        let mut inspect = AsyncInspectReader::new(&mut this.inner, |bytes: &[u8]| hash.update(bytes));
+        match Pin::new(&mut inspect).poll_read(cx, buf) {
+            Poll::Ready(Ok(n)) => {
+                if n == 0 && had_capacity && !this.checked {
+                    this.checked = true;
+                    if let Some(expected) = this.expected.as_ref() {
+                        if this.hash.digest().to_string() != *expected {
+                            return Poll::Ready(Err(io::Error::new(io::ErrorKind::InvalidData, "digest mismatch")));
+                        }
+                    }
+                }
+                Poll::Ready(Ok(n))
+            },
+            poll => poll,
+        }
+    }
+}
+
+/// Digest produced by [`DigestHash`].
+///
+/// Wraps the fixed-size output of a RustCrypto [`digest::Digest`] so it satisfies the
+/// [`chksum_core::Digest`] contract expected by [`Hash::Digest`].
+#[cfg(feature = "digest")]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DigestOutput(Vec<u8>);
+
+#[cfg(feature = "digest")]
+impl AsRef<[u8]> for DigestOutput {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "digest")]
+impl fmt::LowerHex for DigestOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "digest")]
+impl fmt::UpperHex for DigestOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{byte:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "digest")]
+impl fmt::Display for DigestOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl chksum_core::Digest for DigestOutput {}
+
+/// Bridges any RustCrypto [`digest::Digest`] to [`chksum_core::Hash`].
+///
+/// This newtype lets hashers such as `sha2` or `blake3` plug straight into [`Reader`] without
+/// waiting for a dedicated `chksum_core::Hash` implementation:
+///
+/// ```rust,ignore
+/// use chksum_reader::{DigestHash, Reader};
+/// use sha2::Sha256;
+///
+/// let reader = Reader::<_, DigestHash<Sha256>>::new(std::io::stdin());
+/// ```
+///
+/// [`digest`](Self::digest) clones the inner hasher and calls
+/// [`finalize`](digest::Digest::finalize) on the clone so repeated calls stay idempotent even
+/// though [`Hash::digest`] only borrows `&self`.
+#[cfg(feature = "digest")]
+#[derive(Clone, Debug)]
+pub struct DigestHash<D>(D)
+where
+    D: digest::Digest + Clone;
+
+#[cfg(feature = "digest")]
+impl<D> Default for DigestHash<D>
+where
+    D: digest::Digest + Clone,
+{
+    fn default() -> Self {
+        Self(D::new())
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<D> Hash for DigestHash<D>
+where
+    D: digest::Digest + Clone,
+{
+    type Digest = DigestOutput;
+
+    fn update<T>(&mut self, data: T)
+    where
+        T: AsRef<[u8]>,
+    {
+        digest::Digest::update(&mut self.0, data);
+    }
+
+    fn reset(&mut self) {
+        self.0 = D::new();
+    }
+
+    fn digest(&self) -> Self::Digest {
+        let hasher = self.0.clone();
+        DigestOutput(hasher.finalize().to_vec())
+    }
+}
+
+/// Composite [`Hash`] that feeds a single read pass into several hashers at once.
+///
+/// Wrapping a tuple of hashers lets one [`Reader`] produce every digest in a single pass — handy
+/// for manifest generation where an artifact is recorded under multiple algorithms (e.g. MD5 +
+/// SHA-1 + SHA-256). Each [`update`](Hash::update) fans the slice out to every contained hasher and
+/// [`digest`](Hash::digest) returns the tuple of per-algorithm digests, so callers pay the I/O cost
+/// only once:
+///
+/// ```rust,ignore
+/// use chksum_md5::MD5;
+/// use chksum_sha2_256::SHA2_256;
+/// use chksum_reader::{MultiHash, Reader};
+///
+/// let reader = Reader::<_, MultiHash<(MD5, SHA2_256)>>::new(std::io::stdin());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultiHash<T>(T);
+
+/// Digest produced by [`MultiHash`], holding the per-algorithm digests of a single pass.
+///
+/// The individual digests are reachable through the wrapped tuple (`multi_digest.0.0`, …); the
+/// [`Display`](fmt::Display) form concatenates their hexadecimal representations separated by `+`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiDigest<T>(pub T);
+
+impl<A, B> fmt::Display for MultiDigest<(A, B)>
+where
+    A: chksum_core::Digest,
+    B: chksum_core::Digest,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}+{}", self.0.0, self.0.1)
+    }
+}
+
+impl<A, B> chksum_core::Digest for MultiDigest<(A, B)>
+where
+    A: chksum_core::Digest,
+    B: chksum_core::Digest,
+{
+}
+
+impl<A, B, C> fmt::Display for MultiDigest<(A, B, C)>
+where
+    A: chksum_core::Digest,
+    B: chksum_core::Digest,
+    C: chksum_core::Digest,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}+{}+{}", self.0.0, self.0.1, self.0.2)
+    }
+}
+
+impl<A, B, C> chksum_core::Digest for MultiDigest<(A, B, C)>
+where
+    A: chksum_core::Digest,
+    B: chksum_core::Digest,
+    C: chksum_core::Digest,
+{
+}
+
+impl<A, B> Hash for MultiHash<(A, B)>
+where
+    A: Hash,
+    B: Hash,
+{
+    type Digest = MultiDigest<(A::Digest, B::Digest)>;
+
+    fn update<U>(&mut self, data: U)
+    where
+        U: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+        self.0.0.update(data);
+        self.0.1.update(data);
+    }
+
+    fn reset(&mut self) {
+        self.0.0.reset();
+        self.0.1.reset();
+    }
+
+    fn digest(&self) -> Self::Digest {
+        MultiDigest((self.0.0.digest(), self.0.1.digest()))
+    }
+}
+
+impl<A, B, C> Hash for MultiHash<(A, B, C)>
+where
+    A: Hash,
+    B: Hash,
+    C: Hash,
+{
+    type Digest = MultiDigest<(A::Digest, B::Digest, C::Digest)>;
+
+    fn update<U>(&mut self, data: U)
+    where
+        U: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+        self.0.0.update(data);
+        self.0.1.update(data);
+        self.0.2.update(data);
+    }
+
+    fn reset(&mut self) {
+        self.0.0.reset();
+        self.0.1.reset();
+        self.0.2.reset();
+    }
+
+    fn digest(&self) -> Self::Digest {
+        MultiDigest((self.0.0.digest(), self.0.1.digest(), self.0.2.digest()))
+    }
+}
+
+/// Creates new [`InspectReader`].
+pub fn inspect<R, F>(inner: R, inspect: F) -> InspectReader<R, F>
+where
+    R: Read,
+    F: FnMut(&[u8]),
+{
+    InspectReader::new(inner, inspect)
+}
+
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+/// Creates new [`AsyncInspectReader`].
+pub fn async_inspect<R, F>(inner: R, inspect: F) -> AsyncInspectReader<R, F>
+where
+    F: FnMut(&[u8]),
+{
+    AsyncInspectReader::new(inner, inspect)
+}
+
+/// Wraps a reader and invokes a closure on exactly the freshly-read bytes of each read.
+///
+/// Every [`read`](Read::read) hands the newly filled region — never already-consumed data — to the
+/// user closure. [`Reader`] is itself built on top of this type, wrapping a closure that feeds the
+/// region to [`Hash::update`]; exposing the closure form lets callers also mirror bytes into a
+/// progress meter, a secondary writer, or a length counter without stacking a second wrapper.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InspectReader<R, F>
+where
+    R: Read,
+    F: FnMut(&[u8]),
+{
+    inner: R,
+    inspect: F,
+}
+
+impl<R, F> InspectReader<R, F>
+where
+    R: Read,
+    F: FnMut(&[u8]),
+{
+    /// Creates new [`InspectReader`].
+    pub const fn new(inner: R, inspect: F) -> Self {
+        Self { inner, inspect }
+    }
+
+    /// Unwraps this [`InspectReader`], returning the underlying reader.
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        let Self { inner, .. } = self;
+        inner
+    }
+}
+
+impl<R, F> Read for InspectReader<R, F>
+where
+    R: Read,
+    F: FnMut(&[u8]),
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        (self.inspect)(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// Wraps a reader and invokes a closure on exactly the freshly-read bytes of each poll.
+///
+/// [`AsyncReader`] is itself built on top of this type, wrapping a closure that feeds the region to
+/// [`Hash::update`]; see [`InspectReader`] for the synchronous counterpart.
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsyncInspectReader<R, F>
+where
+    F: FnMut(&[u8]),
+{
+    inner: R,
+    inspect: F,
+}
+
+#[cfg(any(feature = "async-runtime-tokio", feature = "async-runtime-async-std"))]
+impl<R, F> AsyncInspectReader<R, F>
+where
+    F: FnMut(&[u8]),
+{
+    /// Creates new [`AsyncInspectReader`].
+    pub const fn new(inner: R, inspect: F) -> Self {
+        Self { inner, inspect }
+    }
+
+    /// Unwraps this [`AsyncInspectReader`], returning the underlying reader.
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        let Self { inner, .. } = self;
+        inner
+    }
+}
+
+#[cfg(feature = "async-runtime-tokio")]
+impl<R, F> AsyncRead for AsyncInspectReader<R, F>
+where
+    R: AsyncRead + Unpin,
+    F: FnMut(&[u8]) + Unpin,
+{
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let Self { inner, inspect } = self.get_mut();
+        let before = buf.filled().len();
         match pin!(inner).poll_read(cx, buf) {
             Poll::Ready(Ok(())) => {
-                hash.update(buf.filled());
+                let filled = buf.filled();
+                inspect(&filled[before..]);
                 Poll::Ready(Ok(()))
             },
             poll => poll,
         }
     }
 }
+
+#[cfg(feature = "async-runtime-async-std")]
+impl<R, F> FuturesAsyncRead for AsyncInspectReader<R, F>
+where
+    R: FuturesAsyncRead + Unpin,
+    F: FnMut(&[u8]) + Unpin,
+{
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let Self { inner, inspect } = self.get_mut();
+        match Pin::new(inner).poll_read(cx, buf) {
+            Poll::Ready(Ok(n)) => {
+                inspect(&buf[..n]);
+                Poll::Ready(Ok(n))
+            },
+            poll => poll,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal byte-summing [`Hash`] used to exercise the reader without pulling in a hash crate.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    struct SumHash(u64);
+
+    /// Digest produced by [`SumHash`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct SumDigest(u64);
+
+    impl fmt::Display for SumDigest {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:016x}", self.0)
+        }
+    }
+
+    impl chksum_core::Digest for SumDigest {}
+
+    impl Hash for SumHash {
+        type Digest = SumDigest;
+
+        fn update<T>(&mut self, data: T)
+        where
+            T: AsRef<[u8]>,
+        {
+            for byte in data.as_ref() {
+                self.0 = self.0.wrapping_add(u64::from(*byte));
+            }
+        }
+
+        fn reset(&mut self) {
+            self.0 = 0;
+        }
+
+        fn digest(&self) -> Self::Digest {
+            SumDigest(self.0)
+        }
+    }
+
+    #[test]
+    fn reads_all_bytes_and_computes_digest() {
+        let data = b"hello world";
+        let mut reader = Reader::<_, SumHash>::new(&data[..]);
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+        assert_eq!(reader.digest(), SumHash::hash(data));
+    }
+
+    #[test]
+    fn verify_accepts_a_matching_expected_digest() {
+        let data = b"content-addressed";
+        let expected = SumHash::hash(data);
+        let mut reader = Reader::<_, SumHash>::with_expected(&data[..], expected);
+        let mut buffer = Vec::new();
+        // The trailing zero-length read at EOF runs verification and must succeed.
+        reader.read_to_end(&mut buffer).unwrap();
+        reader.verify().unwrap();
+    }
+
+    #[test]
+    fn verify_rejects_a_mismatched_digest_at_eof() {
+        let data = b"the real bytes";
+        let expected = SumHash::hash(b"something else entirely");
+        let mut reader = Reader::<_, SumHash>::with_expected(&data[..], expected);
+        let mut buffer = Vec::new();
+        let err = reader.read_to_end(&mut buffer).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn zero_length_read_does_not_trigger_premature_verification() {
+        let data = b"abc";
+        let expected = SumHash::hash(data);
+        let mut reader = Reader::<_, SumHash>::with_expected(&data[..], expected);
+        // A zero-length buffer must not be mistaken for EOF and fail verification early.
+        assert_eq!(reader.read(&mut []).unwrap(), 0);
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer).unwrap();
+    }
+
+    /// A second, distinct [`Hash`] so [`MultiHash`] digests can be told apart.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    struct LenHash(u64);
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct LenDigest(u64);
+
+    impl fmt::Display for LenDigest {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:016x}", self.0)
+        }
+    }
+
+    impl chksum_core::Digest for LenDigest {}
+
+    impl Hash for LenHash {
+        type Digest = LenDigest;
+
+        fn update<T>(&mut self, data: T)
+        where
+            T: AsRef<[u8]>,
+        {
+            self.0 += data.as_ref().len() as u64;
+        }
+
+        fn reset(&mut self) {
+            self.0 = 0;
+        }
+
+        fn digest(&self) -> Self::Digest {
+            LenDigest(self.0)
+        }
+    }
+
+    #[test]
+    fn multi_hash_reports_each_digest_from_one_pass() {
+        let data = b"several checksums";
+        let mut reader = Reader::<_, MultiHash<(SumHash, LenHash)>>::new(&data[..]);
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer).unwrap();
+        let MultiDigest((sum, len)) = reader.digest();
+        assert_eq!(sum, SumHash::hash(data));
+        assert_eq!(len, LenHash::hash(data));
+    }
+
+    /// A third, distinct [`Hash`] so the 3-tuple [`MultiHash`] digests can be told apart.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    struct XorHash(u8);
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct XorDigest(u8);
+
+    impl fmt::Display for XorDigest {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:02x}", self.0)
+        }
+    }
+
+    impl chksum_core::Digest for XorDigest {}
+
+    impl Hash for XorHash {
+        type Digest = XorDigest;
+
+        fn update<T>(&mut self, data: T)
+        where
+            T: AsRef<[u8]>,
+        {
+            for byte in data.as_ref() {
+                self.0 ^= byte;
+            }
+        }
+
+        fn reset(&mut self) {
+            self.0 = 0;
+        }
+
+        fn digest(&self) -> Self::Digest {
+            XorDigest(self.0)
+        }
+    }
+
+    #[test]
+    fn multi_hash_reports_each_digest_from_one_pass_for_three_hashers() {
+        let data = b"several checksums at once";
+        let mut reader = Reader::<_, MultiHash<(SumHash, LenHash, XorHash)>>::new(&data[..]);
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer).unwrap();
+        let MultiDigest((sum, len, xor)) = reader.digest();
+        assert_eq!(sum, SumHash::hash(data));
+        assert_eq!(len, LenHash::hash(data));
+        assert_eq!(xor, XorHash::hash(data));
+    }
+
+    #[test]
+    fn inspect_reader_sees_only_the_freshly_filled_region() {
+        let data = b"inspect me";
+        let mut seen = Vec::new();
+        {
+            let mut reader = InspectReader::new(&data[..], |chunk: &[u8]| seen.extend_from_slice(chunk));
+            // Read in small chunks so the closure is invoked several times.
+            let mut buffer = [0u8; 4];
+            loop {
+                let n = reader.read(&mut buffer).unwrap();
+                if n == 0 {
+                    break;
+                }
+            }
+        }
+        // The callback must observe each byte exactly once, in order.
+        assert_eq!(seen, data);
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn digest_hash_matches_rustcrypto_and_is_idempotent() {
+        use sha2::{Digest as _, Sha256};
+
+        let data = b"bridge me to RustCrypto";
+        let mut reader = Reader::<_, DigestHash<Sha256>>::new(&data[..]);
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer).unwrap();
+
+        let expected = Sha256::digest(data);
+        assert_eq!(reader.digest().as_ref(), expected.as_slice());
+        // Repeated calls must stay stable since `digest` only borrows `&self`.
+        assert_eq!(reader.digest(), reader.digest());
+    }
+
+    #[cfg(feature = "async-runtime-tokio")]
+    #[test]
+    fn reader_stream_yields_chunks_then_none() {
+        use std::task::Waker;
+
+        let data = b"streamed bytes";
+        let reader = AsyncReader::<_, SumHash>::new(&data[..]);
+        let mut stream = reader.into_stream_with_capacity(4);
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut collected = Vec::new();
+        loop {
+            match Pin::new(&mut stream).poll_next(&mut cx) {
+                Poll::Ready(Some(Ok(chunk))) => collected.extend_from_slice(&chunk),
+                Poll::Ready(None) => break,
+                Poll::Ready(Some(Err(err))) => panic!("unexpected stream error: {err}"),
+                Poll::Pending => panic!("a reader over a slice never pends"),
+            }
+        }
+        assert_eq!(collected, data);
+        // The digest stays retrievable once the stream is exhausted.
+        assert_eq!(stream.digest(), SumHash::hash(data));
+    }
+
+    #[cfg(feature = "async-runtime-tokio")]
+    #[test]
+    fn reader_stream_with_zero_capacity_still_reads_all_bytes() {
+        use std::task::Waker;
+
+        let data = b"streamed bytes";
+        let reader = AsyncReader::<_, SumHash>::new(&data[..]);
+        // A requested capacity of 0 must not be mistaken for immediate EOF.
+        let mut stream = reader.into_stream_with_capacity(0);
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut collected = Vec::new();
+        loop {
+            match Pin::new(&mut stream).poll_next(&mut cx) {
+                Poll::Ready(Some(Ok(chunk))) => collected.extend_from_slice(&chunk),
+                Poll::Ready(None) => break,
+                Poll::Ready(Some(Err(err))) => panic!("unexpected stream error: {err}"),
+                Poll::Pending => panic!("a reader over a slice never pends"),
+            }
+        }
+        assert_eq!(collected, data);
+    }
+
+    #[cfg(feature = "async-runtime-tokio")]
+    #[test]
+    fn tokio_reads_all_bytes_and_computes_digest() {
+        use std::task::Waker;
+
+        let data = b"hello tokio";
+        let mut reader = AsyncReader::<_, SumHash>::new(&data[..]);
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut collected = Vec::new();
+        let mut backing = [0u8; 4];
+        loop {
+            let mut buf = ReadBuf::new(&mut backing);
+            match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+                Poll::Ready(Ok(())) if buf.filled().is_empty() => break,
+                Poll::Ready(Ok(())) => collected.extend_from_slice(buf.filled()),
+                Poll::Ready(Err(err)) => panic!("unexpected read error: {err}"),
+                Poll::Pending => panic!("a reader over a slice never pends"),
+            }
+        }
+        assert_eq!(collected, data);
+        assert_eq!(reader.digest(), SumHash::hash(data));
+    }
+
+    #[cfg(feature = "async-runtime-tokio")]
+    #[test]
+    fn tokio_verify_rejects_a_mismatched_digest_at_eof() {
+        use std::task::Waker;
+
+        let data = b"the real bytes";
+        let expected = SumHash::hash(b"something else entirely");
+        let mut reader = AsyncReader::<_, SumHash>::with_expected(&data[..], expected);
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut backing = [0u8; 64];
+        loop {
+            let mut buf = ReadBuf::new(&mut backing);
+            match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+                Poll::Ready(Ok(())) if buf.filled().is_empty() => panic!("expected a digest mismatch error at EOF"),
+                Poll::Ready(Ok(())) => continue,
+                Poll::Ready(Err(err)) => {
+                    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+                    break;
+                },
+                Poll::Pending => panic!("a reader over a slice never pends"),
+            }
+        }
+    }
+
+    #[cfg(feature = "async-runtime-async-std")]
+    #[test]
+    fn async_std_reads_all_bytes_and_computes_digest() {
+        use std::task::Waker;
+
+        let data = b"hello futures-io";
+        let mut reader = AsyncReader::<_, SumHash>::new(&data[..]);
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut collected = Vec::new();
+        let mut buf = [0u8; 4];
+        loop {
+            match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+                Poll::Ready(Ok(0)) => break,
+                Poll::Ready(Ok(n)) => collected.extend_from_slice(&buf[..n]),
+                Poll::Ready(Err(err)) => panic!("unexpected read error: {err}"),
+                Poll::Pending => panic!("a reader over a slice never pends"),
+            }
+        }
+        assert_eq!(collected, data);
+        assert_eq!(reader.digest(), SumHash::hash(data));
+    }
+
+    #[cfg(feature = "async-runtime-async-std")]
+    #[test]
+    fn async_std_verify_rejects_a_mismatched_digest_at_eof() {
+        use std::task::Waker;
+
+        let data = b"the real bytes";
+        let expected = SumHash::hash(b"something else entirely");
+        let mut reader = AsyncReader::<_, SumHash>::with_expected(&data[..], expected);
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut buf = [0u8; 64];
+        loop {
+            match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+                Poll::Ready(Ok(0)) => panic!("expected a digest mismatch error at EOF"),
+                Poll::Ready(Ok(_)) => continue,
+                Poll::Ready(Err(err)) => {
+                    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+                    break;
+                },
+                Poll::Pending => panic!("a reader over a slice never pends"),
+            }
+        }
+    }
+
+    #[cfg(feature = "async-runtime-async-std")]
+    #[test]
+    fn async_std_reader_stream_yields_chunks_then_none() {
+        use std::task::Waker;
+
+        let data = b"streamed bytes";
+        let reader = AsyncReader::<_, SumHash>::new(&data[..]);
+        let mut stream = reader.into_stream_with_capacity(4);
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut collected = Vec::new();
+        loop {
+            match Pin::new(&mut stream).poll_next(&mut cx) {
+                Poll::Ready(Some(Ok(chunk))) => collected.extend_from_slice(&chunk),
+                Poll::Ready(None) => break,
+                Poll::Ready(Some(Err(err))) => panic!("unexpected stream error: {err}"),
+                Poll::Pending => panic!("a reader over a slice never pends"),
+            }
+        }
+        assert_eq!(collected, data);
+        // The digest stays retrievable once the stream is exhausted.
+        assert_eq!(stream.digest(), SumHash::hash(data));
+    }
+}